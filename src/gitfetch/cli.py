@@ -10,7 +10,7 @@ from typing import Optional
 
 import readchar
 
-from .display import DisplayFormatter
+from .display import DisplayFormatter, no_color_requested
 from .cache import CacheManager
 from .config import ConfigManager
 from .providers import ProviderConfig, PROVIDER_ENV_VARS, PROVIDER_DEFAULT_URLS
@@ -59,6 +59,33 @@ def _debug_enabled() -> bool:
     return value.strip().lower() not in {'', '0', 'false', 'no', 'off'}
 
 
+def _heading(text: str, color_code: str) -> str:
+    """Color a help section heading unless colors are disabled."""
+    # Headings are built before parsing, so --no-color is read from argv
+    if no_color_requested() or '--no-color' in sys.argv[1:]:
+        return text
+    return f"\033[{color_code}m{text}\033[0m"
+
+
+def _apply_git_location(git_dir: Optional[str],
+                        work_tree: Optional[str]) -> None:
+    """
+    Point every git invocation at an explicit repository.
+
+    git reads $GIT_DIR and $GIT_WORK_TREE exactly like its --git-dir and
+    --work-tree options, so exporting them covers all subprocess calls.
+
+    Args:
+        git_dir: Path to the git directory, or None to leave unchanged
+        work_tree: Path to the work tree, or None to leave unchanged
+    """
+    if git_dir:
+        os.environ['GIT_DIR'] = os.path.abspath(os.path.expanduser(git_dir))
+    if work_tree:
+        os.environ['GIT_WORK_TREE'] = os.path.abspath(
+            os.path.expanduser(work_tree))
+
+
 def parse_args() -> argparse.Namespace:
     """Parse command-line arguments."""
     parser = argparse.ArgumentParser(
@@ -73,7 +100,7 @@ Supports GitHub, GitLab, Gitea, and Sourcehut.""",
         help="Username to fetch stats for"
     )
 
-    general_group = parser.add_argument_group('\033[92mGeneral Options\033[0m')
+    general_group = parser.add_argument_group(_heading('General Options', '92'))
     general_group.add_argument(
         "--no-cache",
         action="store_true",
@@ -108,10 +135,24 @@ Supports GitHub, GitLab, Gitea, and Sourcehut.""",
     general_group.add_argument(
         "--local",
         action="store_true",
-        help="Fetch data specific to current local repo (requires .git folder)"
+        help="Fetch data specific to current local repo (requires a git repository)"
+    )
+
+    general_group.add_argument(
+        "--git-dir",
+        type=str,
+        help="Path to the repository's git directory, e.g. a bare dotfiles "
+             "repo (same as $GIT_DIR; only with --local or --graph-timeline)"
+    )
+
+    general_group.add_argument(
+        "--work-tree",
+        type=str,
+        help="Path to the work tree for --git-dir (same as $GIT_WORK_TREE; "
+             "only with --local or --graph-timeline)"
     )
 
-    visual_group = parser.add_argument_group('\033[94mVisual Options\033[0m')
+    visual_group = parser.add_argument_group(_heading('Visual Options', '94'))
     visual_group.add_argument(
         "--spaced",
         action="store_true",
@@ -168,7 +209,13 @@ Supports GitHub, GitLab, Gitea, and Sourcehut.""",
         help="Show git timeline graph instead of contribution graph"
     )
 
-    visibility_group = parser.add_argument_group('\033[95mVisibility\033[0m')
+    visual_group.add_argument(
+        "--no-color",
+        action="store_true",
+        help="Disable colored output (also enabled by the NO_COLOR env var)"
+    )
+
+    visibility_group = parser.add_argument_group(_heading('Visibility', '95'))
     visibility_group.add_argument(
         "--no-date",
         action="store_true",
@@ -219,16 +266,34 @@ def main() -> int:
     try:
         args = parse_args()
 
+        # Only local history and the timeline graph read a repository
+        if (args.git_dir or args.work_tree) and not (
+                args.local or args.graph_timeline):
+            print("Error: --git-dir and --work-tree require --local or "
+                  "--graph-timeline", file=sys.stderr)
+            return 1
+        _apply_git_location(args.git_dir, args.work_tree)
+
+        # Local history and the timeline graph both shell out to git
+        if args.local or args.graph_timeline:
+            from .fetcher import is_git_available, git_not_found_message
+            if not is_git_available():
+                print(git_not_found_message(), file=sys.stderr)
+                return 1
+
         # Check for --local flag (must come before config init)
         if args.local:
-            import os
-            if not os.path.exists('.git'):
-                print("Error: --local requires .git folder", file=sys.stderr)
+            from .fetcher import BaseFetcher, get_local_repo_root
+
+            # Resolved through git so $GIT_DIR/$GIT_WORK_TREE and
+            # subdirectories of a work tree are honored
+            repo_root = get_local_repo_root()
+            if not repo_root:
+                print("Error: --local requires a git repository",
+                      file=sys.stderr)
                 return 1
 
             # Local mode: no config, no remote API — read from .git directly
-            from .fetcher import BaseFetcher
-
             config_manager = ConfigManager()
             formatter = DisplayFormatter(
                 config_manager,
@@ -245,6 +310,7 @@ def main() -> int:
                 custom_height=args.height,
                 graph_timeline=args.graph_timeline,
                 local_mode=True,
+                no_color=args.no_color,
             )
             spaced = True
             if args.spaced:
@@ -258,7 +324,7 @@ def main() -> int:
                 return 0
 
             stats = {'contribution_graph': weeks}
-            repo_name = os.path.basename(os.path.abspath('.'))
+            repo_name = os.path.basename(repo_root)
             user_data = {
                 'name': repo_name,
                 'bio': 'local repository',
@@ -292,13 +358,16 @@ def main() -> int:
                 if resp.status_code == 200:
                     latest = resp.json()["tag_name"].lstrip("v")
                     if latest != __version__:
-                        print(f"\033[93mUpdate available: {latest}\n"
+                        plain = args.no_color or no_color_requested()
+                        yellow = '' if plain else '\033[93m'
+                        reset = '' if plain else '\033[0m'
+                        print(f"{yellow}Update available: {latest}\n"
                               + "Get it at: https://github.com/Matars/gitfetch/releases/latest\n"
                               + "Or update using your package manager:\n"
                               + "\t\tbrew update && brew upgrade gitfetch\n"
                               + "\t\tpip install --upgrade gitfetch\n"
                               + "\t\tpacman -Syu gitfetch-python\n"
-                              + "\t\tsudo apt update && sudo apt install --only-upgrade gitfetch\033[0m")
+                              + f"\t\tsudo apt update && sudo apt install --only-upgrade gitfetch{reset}")
                     else:
                         print("You are using the latest version.")
                 else:
@@ -357,6 +426,7 @@ def main() -> int:
             args.local,
             args.shape,
             args.text,
+            no_color=args.no_color,
         )
         if args.spaced:
             spaced = True
@@ -119,7 +119,10 @@ class ConfigManager:
         Returns:
             dict: color name to ANSI code
         """
-        # Map hex codes to ANSI codes (basic mapping for demonstration)
+        # Imported here to avoid a circular import with display
+        from .display import hex_to_ansi as rgb_hex_to_ansi
+
+        # Default palette entries that map onto basic ANSI codes
         hex_to_ansi = {
             '#000000': '\033[0m',
             '#FFFFFF': '\033[1m',
@@ -131,16 +134,14 @@ class ConfigManager:
             '#FF79C6': '\033[95m',
             '#8BE9FD': '\033[96m',
             '#F8F8F2': '\033[97m',
-            '#FFB86C': '\033[38;2;255;184;108m',
-            '#76D7A1': '\033[38;2;118;215;161m',
-            '#44475A': '\033[38;2;68;71;90m',
             '#282A36': '\033[48;5;238m',
-            '#6272A4': '\033[38;2;98;114;164m',
         }
         colors = self.get_colors()
         ansi_colors = {}
         for key, value in colors.items():
-            ansi_colors[key] = hex_to_ansi.get(value, value)
+            # Anything else is converted according to the terminal's
+            # color depth (truecolor, 256 or 16 colors)
+            ansi_colors[key] = hex_to_ansi.get(value) or rgb_hex_to_ansi(value)
         return ansi_colors
 
     def set_default_username(self, username: str) -> None:
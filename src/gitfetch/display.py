@@ -3,9 +3,11 @@ Display formatter for git provider statistics in neofetch style
 """
 
 from typing import Dict, Any, Optional
+import os
 import shutil
 import sys
 import re
+import colorsys
 import unicodedata
 from datetime import datetime
 from .config import ConfigManager
@@ -13,8 +15,105 @@ from .text_patterns import CHAR_PATTERNS
 import subprocess
 import webcolors
 
+# Basic ANSI hues in 60 degree steps starting at red, as code offsets
+# (red, yellow, green, cyan, blue, magenta). Offsets 0-7 map to
+# 30-37/40-47, offsets 60-67 to the bright 90-97/100-107.
+ANSI_16_HUES = [1, 3, 2, 6, 4, 5]
+
+# Gray ramp of the 16-color palette (xterm defaults) as (offset, lightness)
+ANSI_16_GRAYS = [(0, 0.0), (60, 0.5), (7, 0.9), (67, 1.0)]
+
+# Channel levels of the 6x6x6 color cube in the 256-color palette
+ANSI_256_LEVELS = [0, 95, 135, 175, 215, 255]
+
+
+def get_color_depth() -> str:
+    """
+    Guess the color depth supported by the terminal.
+
+    Returns:
+        'truecolor', '256' or '16'
+    """
+    colorterm = os.environ.get('COLORTERM', '').lower()
+    # Windows Terminal supports truecolor but does not set COLORTERM
+    if colorterm in ('truecolor', '24bit') or os.environ.get('WT_SESSION'):
+        return 'truecolor'
+    if '256' in os.environ.get('TERM', ''):
+        return '256'
+    return '16'
+
+
+def no_color_requested() -> bool:
+    """Check the NO_COLOR convention (https://no-color.org)."""
+    return bool(os.environ.get('NO_COLOR'))
+
+
+def _color_distance(a: tuple, b: tuple) -> int:
+    """Squared euclidean distance between two RGB tuples."""
+    return sum((x - y) ** 2 for x, y in zip(a, b))
+
+
+def rgb_to_ansi256(r: int, g: int, b: int) -> int:
+    """Map an RGB color to the nearest entry of the 256-color palette."""
+    def nearest_level(value: int) -> int:
+        return min(range(6), key=lambda i: abs(ANSI_256_LEVELS[i] - value))
+
+    ri, gi, bi = nearest_level(r), nearest_level(g), nearest_level(b)
+    cube_rgb = (ANSI_256_LEVELS[ri], ANSI_256_LEVELS[gi], ANSI_256_LEVELS[bi])
+    cube_index = 16 + 36 * ri + 6 * gi + bi
+
+    # Grayscale ramp 232-255 covers 8..238 in steps of 10
+    gray_step = max(0, min(23, round(((r + g + b) / 3 - 8) / 10)))
+    gray_value = 8 + gray_step * 10
+    gray_rgb = (gray_value, gray_value, gray_value)
+
+    if _color_distance((r, g, b), gray_rgb) < _color_distance((r, g, b),
+                                                              cube_rgb):
+        return 232 + gray_step
+    return cube_index
+
+
+def rgb_to_ansi16(r: int, g: int, b: int) -> int:
+    """
+    Map an RGB color to the offset of the closest basic ANSI color.
+
+    Plain RGB distance pulls every mid-saturation color towards gray, so
+    colored input is matched by hue and only grayish input by lightness.
+    """
+    hue, lightness, _ = colorsys.rgb_to_hls(r / 255, g / 255, b / 255)
+    _, saturation, value = colorsys.rgb_to_hsv(r / 255, g / 255, b / 255)
+    if saturation < 0.25 or value < 0.2:
+        offset, _ = min(ANSI_16_GRAYS,
+                        key=lambda entry: abs(entry[1] - lightness))
+        return offset
+    offset = ANSI_16_HUES[round(hue * 6) % 6]
+    return offset + 60 if lightness >= 0.5 else offset
+
+
+def _ansi16_intensity(r: int, g: int, b: int) -> str:
+    """
+    Pick a bold or dim attribute to keep shades of one hue apart.
+
+    Only the foreground honors these, so backgrounds are limited to the
+    normal and bright variant of each hue. Grays already have a ramp.
+    """
+    if rgb_to_ansi16(r, g, b) % 60 not in ANSI_16_HUES:
+        return ''
+    lightness = (max(r, g, b) + min(r, g, b)) / 510
+    if lightness >= 0.7:
+        return '1;'
+    if lightness < 0.3:
+        return '2;'
+    return ''
+
+
 def hex_to_ansi(hex_color: str, background: bool = False) -> str:
-    """Convert hex color to ANSI escape code."""
+    """
+    Convert hex color to ANSI escape code.
+
+    Emits 24-bit codes on truecolor terminals and falls back to the
+    nearest 256-color or 16-color code elsewhere.
+    """
     if not hex_color.startswith('#'):
         return hex_color  # Already ANSI or invalid
     hex_color = hex_color.lstrip('#')
@@ -24,13 +123,24 @@ def hex_to_ansi(hex_color: str, background: bool = False) -> str:
         r = int(hex_color[0:2], 16)
         g = int(hex_color[2:4], 16)
         b = int(hex_color[4:6], 16)
-        if background:
-            return f'\033[48;2;{r};{g};{b}m'
-        else:
-            return f'\033[38;2;{r};{g};{b}m'
     except ValueError:
         return hex_color
 
+    depth = get_color_depth()
+    if depth == 'truecolor':
+        if background:
+            return f'\033[48;2;{r};{g};{b}m'
+        return f'\033[38;2;{r};{g};{b}m'
+    if depth == '256':
+        index = rgb_to_ansi256(r, g, b)
+        if background:
+            return f'\033[48;5;{index}m'
+        return f'\033[38;5;{index}m'
+    offset = rgb_to_ansi16(r, g, b)
+    if background:
+        return f'\033[{40 + offset}m'
+    return f'\033[{_ansi16_intensity(r, g, b)}{30 + offset}m'
+
 
 class DisplayFormatter:
     """Formats and displays git provider stats in a neofetch-style layout."""
@@ -51,14 +161,16 @@ class DisplayFormatter:
                  local_mode: bool = False,
                  shape: Optional[list] = None,
                  text: Optional[str] = None,
-                 text_patterns: Optional[dict] = None):
+                 text_patterns: Optional[dict] = None,
+                 no_color: bool = False):
         """Initialize the display formatter."""
         terminal_size = shutil.get_terminal_size()
         self.terminal_width = terminal_size.columns
         self.terminal_height = terminal_size.lines
         # Reserve some lines for prompt/shell status
         self.available_height = max(10, self.terminal_height - 2)
-        self.enable_color = sys.stdout.isatty()
+        self.enable_color = (sys.stdout.isatty() and not no_color
+                             and not no_color_requested())
         self.colors = config_manager.get_ansi_colors()
         self.hex_colors = config_manager.get_colors()
         self.custom_box = custom_box or config_manager.get_custom_box() or "■"
@@ -353,14 +465,17 @@ class DisplayFormatter:
             print(f"{graph_part}{padding}  {info_part}")
 
     def _reverse_truncate(self, line: str, max_width: int):
-        pattern = r"(\x1b\[[0-9;]*m)(.*?)(?=(\x1b\[[0-9;]*m)|$)"
-        matches = re.findall(pattern, line, flags=re.DOTALL)
-        segments = [(m[0], m[1]) for m in matches]
+        ANSI_PATTERN = re.compile(r'\033\[[0-9;]*m')
+        parts = ANSI_PATTERN.split(line)
+        codes = ANSI_PATTERN.findall(line)
+        # Text before the first escape code (or the whole line when git
+        # ran with --color=never) is a segment without a color of its own
+        segments = [('', parts[0])] + list(zip(codes, parts[1:]))
 
-        width = sum(len(text) for _, text in segments)
+        width = sum(self._display_width(text) for _, text in segments)
 
         if width <= max_width:
-            return line.rjust(max_width)
+            return " " * (max_width - width) + line
 
         result = []
         remaining = max_width
@@ -368,15 +483,23 @@ class DisplayFormatter:
         for color, text in reversed(segments):
             if remaining <= 0:
                 break
-            if len(text) <= remaining:
+            text_width = self._display_width(text)
+            if text_width <= remaining:
                 result.append((color, text))
-                remaining -= len(text)
+                remaining -= text_width
             else:
-                result.append((color, text[-remaining:]))
+                # Keep as many trailing characters as fit in the cells left
+                kept = ''
+                for char in reversed(text):
+                    if self._display_width(char + kept) > remaining:
+                        break
+                    kept = char + kept
+                result.append((color, kept))
                 remaining = 0
 
         final = ''.join(color + text for color, text in reversed(result))
-        final += self.colors.get('reset', '\x1b[0m')
+        if self.enable_color:
+            final += self.colors.get('reset', '\x1b[0m')
         return final
 
     def _display_full(self, username: str, user_data: Dict[str, Any],
@@ -417,9 +540,9 @@ class DisplayFormatter:
 
                 if right_side and left_side:
                     print()  # Add spacing
+                    reset = self.colors['reset'] if self.enable_color else ''
                     for l, r in zip(left_side, right_side):
-                        print(self._reverse_truncate(l, max_width),
-                              self.colors['reset'], r)
+                        print(self._reverse_truncate(l, max_width), reset, r)
             except Exception as e:
                 print(f"Error displaying timeline: {e}")
             return
@@ -597,8 +720,10 @@ class DisplayFormatter:
         return BaseFetcher._build_contribution_graph_from_git()
 
     def _get_graph_text(self, vertical=False):
+        from .fetcher import get_git_executable
+        color_flag = '--color=always' if self.enable_color else '--color=never'
         text = subprocess.check_output(
-            ['git', '--no-pager', 'log', '--color=always',
+            [get_git_executable(), '--no-pager', 'log', color_flag,
                 '--graph', '--all', '--pretty=format:""']
         ).decode().replace('"', '')
 
@@ -705,7 +830,7 @@ class DisplayFormatter:
             f"{name} - {total_contributions:,} contributions this year"
         )
         lines.append(
-            self._colorize("─" * len(plain_line), "muted")
+            self._colorize("─" * self._display_width(plain_line), "muted")
         )
 
         def add_line(label: str, value: str) -> None:
@@ -715,7 +840,8 @@ class DisplayFormatter:
         bio = user_data.get('bio', '')
         if bio:
             trimmed_bio = bio.strip().replace('\n', ' ')
-            lines.append(f"{self._label('Bio')} {trimmed_bio[:80]}")
+            lines.append(
+                f"{self._label('Bio')} {self._truncate_text(trimmed_bio, 80)}")
 
         add_line('Company', user_data.get('company'))
         add_line('Website', user_data.get('blog'))
@@ -1492,7 +1618,11 @@ class DisplayFormatter:
         clean = self._strip_ansi(text)
         width = 0
         for char in clean:
-            if unicodedata.combining(char):
+            # Combining marks, zero-width spaces/joiners, word joiners and
+            # variation selectors take no cell of their own
+            if (unicodedata.category(char) in ('Mn', 'Me') or
+                    '\u200b' <= char <= '\u200d' or char == '\u2060' or
+                    '\ufe00' <= char <= '\ufe0f'):
                 continue
 
             east_asian = unicodedata.east_asian_width(char)
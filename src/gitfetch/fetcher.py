@@ -11,6 +11,73 @@ import os
 import re
 
 
+def get_git_executable() -> str:
+    """
+    Get the git executable to run.
+
+    Returns:
+        Value of the $GIT environment variable if set, otherwise 'git'
+    """
+    return os.environ.get('GIT') or 'git'
+
+
+def is_git_available() -> bool:
+    """
+    Check whether the git executable can be run.
+
+    Returns:
+        True if 'git --version' succeeds, False otherwise
+    """
+    try:
+        result = subprocess.run(
+            [get_git_executable(), '--version'],
+            capture_output=True, text=True, timeout=5
+        )
+    except (OSError, subprocess.TimeoutExpired):
+        return False
+    return result.returncode == 0
+
+
+def git_not_found_message() -> str:
+    """Build the error shown when the git executable is missing."""
+    if os.environ.get('GIT'):
+        return f"Error: git executable '{get_git_executable()}' (from $GIT) not found"
+    return "Error: git not found on PATH"
+
+
+def get_local_repo_root(repo_path: str = ".") -> Optional[str]:
+    """
+    Locate the local git repository containing repo_path.
+
+    Uses git itself so $GIT_DIR / $GIT_WORK_TREE and subdirectories of a
+    work tree are handled the same way git handles them.
+
+    Args:
+        repo_path: Path inside the repository (default: current dir)
+
+    Returns:
+        Work tree root, or the git dir for bare repositories, or None
+        when repo_path is not inside a git repository
+    """
+    def rev_parse(option: str) -> Optional[str]:
+        try:
+            result = subprocess.run(
+                [get_git_executable(), 'rev-parse', option],
+                capture_output=True, text=True, cwd=repo_path
+            )
+        except OSError:
+            return None
+        if result.returncode != 0:
+            return None
+        return result.stdout.strip() or None
+
+    git_dir = rev_parse('--absolute-git-dir')
+    if not git_dir:
+        return None
+    # Bare repositories have no work tree, fall back to the git dir itself
+    return rev_parse('--show-toplevel') or git_dir
+
+
 class BaseFetcher(ABC):
     """Abstract base class for git hosting provider fetchers."""
 
@@ -77,7 +144,7 @@ class BaseFetcher(ABC):
         try:
             # Get commit dates
             result = subprocess.run(
-                ['git', 'log', '--pretty=format:%ai', '--all'],
+                [get_git_executable(), 'log', '--pretty=format:%ai', '--all'],
                 capture_output=True, text=True, cwd=repo_path
             )
             if result.returncode != 0:
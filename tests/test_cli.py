@@ -0,0 +1,56 @@
+"""
+Tests for command-line helpers
+"""
+
+import os
+from unittest.mock import patch
+
+from gitfetch.cli import _apply_git_location, _heading, main
+
+
+class TestGitLocation:
+    """Test cases for --git-dir / --work-tree handling."""
+
+    def test_flags_export_absolute_paths(self):
+        """Test that the flags become absolute $GIT_DIR/$GIT_WORK_TREE."""
+        with patch.dict('os.environ', {}, clear=True):
+            _apply_git_location('dotfiles.git', '~')
+            assert os.environ['GIT_DIR'] == os.path.abspath('dotfiles.git')
+            assert os.environ['GIT_WORK_TREE'] == os.path.expanduser('~')
+
+    def test_unset_flags_keep_environment(self):
+        """Test that existing git environment is left alone without flags."""
+        env = {'GIT_DIR': '/srv/repo.git'}
+        with patch.dict('os.environ', env, clear=True):
+            _apply_git_location(None, None)
+            assert os.environ['GIT_DIR'] == '/srv/repo.git'
+            assert 'GIT_WORK_TREE' not in os.environ
+
+    def test_flags_require_local_mode(self):
+        """Test that --git-dir without --local/--graph-timeline errors out."""
+        argv = ['gitfetch', 'someuser', '--git-dir', 'dotfiles.git']
+        with patch.dict('os.environ', {}, clear=True), \
+                patch('sys.argv', argv), \
+                patch('gitfetch.cli.ConfigManager') as config_manager:
+            assert main() == 1
+            assert 'GIT_DIR' not in os.environ
+        config_manager.assert_not_called()
+
+
+class TestHelpHeadings:
+    """Test cases for colored help section headings."""
+
+    def test_heading_colored_by_default(self):
+        """Test that headings are colored without opt-outs."""
+        with patch.dict('os.environ', {}, clear=True), \
+                patch('sys.argv', ['gitfetch', '--help']):
+            assert _heading('Visibility', '95') == '\033[95mVisibility\033[0m'
+
+    def test_heading_plain_with_no_color(self):
+        """Test that --no-color and NO_COLOR both drop heading colors."""
+        with patch.dict('os.environ', {}, clear=True), \
+                patch('sys.argv', ['gitfetch', '--no-color', '--help']):
+            assert _heading('Visibility', '95') == 'Visibility'
+        with patch.dict('os.environ', {'NO_COLOR': '1'}, clear=True), \
+                patch('sys.argv', ['gitfetch', '--help']):
+            assert _heading('Visibility', '95') == 'Visibility'
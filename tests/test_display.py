@@ -0,0 +1,154 @@
+"""
+Tests for display color handling
+"""
+
+from unittest.mock import MagicMock, patch
+
+from gitfetch.display import (DisplayFormatter, hex_to_ansi, get_color_depth,
+                              rgb_to_ansi256, rgb_to_ansi16)
+
+
+class TestColorDepth:
+    """Test cases for terminal color depth detection and fallback."""
+
+    def test_truecolor_detection(self):
+        """Test that COLORTERM=truecolor enables 24-bit output."""
+        with patch.dict('os.environ', {'COLORTERM': 'truecolor'}, clear=True):
+            assert get_color_depth() == 'truecolor'
+            assert hex_to_ansi('#FFB86C') == '\033[38;2;255;184;108m'
+            assert hex_to_ansi('#FFB86C', background=True) == \
+                '\033[48;2;255;184;108m'
+
+    def test_256_color_fallback(self):
+        """Test fallback to the 256-color palette."""
+        env = {'TERM': 'xterm-256color'}
+        with patch.dict('os.environ', env, clear=True):
+            assert get_color_depth() == '256'
+            assert hex_to_ansi('#FF0000') == '\033[38;5;196m'
+            assert hex_to_ansi('#808080', background=True) == '\033[48;5;244m'
+
+    def test_16_color_fallback(self):
+        """Test fallback to basic ANSI colors on plain terminals."""
+        with patch.dict('os.environ', {'TERM': 'xterm'}, clear=True):
+            assert get_color_depth() == '16'
+            assert hex_to_ansi('#FF0000') == '\033[91m'
+            assert hex_to_ansi('#000000', background=True) == '\033[40m'
+
+    def test_16_color_contribution_levels(self):
+        """Test that default graph levels stay green and distinct."""
+        levels = ['#ebedf0', '#9be9a8', '#40c463', '#30a14e', '#216e39']
+        with patch.dict('os.environ', {'TERM': 'xterm'}, clear=True):
+            codes = [hex_to_ansi(level) for level in levels]
+            backgrounds = [hex_to_ansi(level, background=True)
+                           for level in levels[1:]]
+            header = hex_to_ansi('#76D7A1')
+            muted = hex_to_ansi('#44475A')
+        assert len(set(codes)) == 5
+        for code in codes[1:]:
+            assert code.endswith(('32m', '92m'))
+        for code in backgrounds:
+            assert code in ('\033[42m', '\033[102m')
+        assert header == '\033[92m'
+        assert muted == '\033[90m'
+
+    def test_nearest_palette_entries(self):
+        """Test nearest color lookups for cube and grayscale entries."""
+        assert rgb_to_ansi256(0, 0, 0) == 16
+        assert rgb_to_ansi256(255, 255, 255) == 231
+        assert rgb_to_ansi256(128, 128, 128) == 244
+        assert rgb_to_ansi16(0, 0, 230) == 4
+        assert rgb_to_ansi16(0x9b, 0xe9, 0xa8) == 62
+        assert rgb_to_ansi16(0x44, 0x47, 0x5a) == 60
+
+    def test_non_hex_passthrough(self):
+        """Test that values which aren't hex colors are returned as-is."""
+        assert hex_to_ansi('\033[92m') == '\033[92m'
+
+
+def _make_formatter(**kwargs):
+    """Build a formatter on a fake terminal without touching user config."""
+    config_manager = MagicMock()
+    config_manager.get_ansi_colors.return_value = {'reset': '\033[0m',
+                                                   'header': '\033[92m'}
+    config_manager.get_colors.return_value = {}
+    config_manager.get_custom_box.return_value = None
+    with patch('sys.stdout.isatty', return_value=True):
+        return DisplayFormatter(config_manager, show_date=True, **kwargs)
+
+
+class TestNoColor:
+    """Test cases for disabling colored output."""
+
+    def test_color_enabled_on_tty(self):
+        """Test that color stays on for a terminal by default."""
+        with patch.dict('os.environ', {}, clear=True):
+            formatter = _make_formatter()
+        assert formatter.enable_color
+        assert formatter._colorize('hi', 'header') == '\033[92mhi\033[0m'
+
+    def test_no_color_flag(self):
+        """Test that --no-color disables styling."""
+        with patch.dict('os.environ', {}, clear=True):
+            formatter = _make_formatter(no_color=True)
+        assert not formatter.enable_color
+        assert formatter._colorize('hi', 'header') == 'hi'
+
+    def test_no_color_env(self):
+        """Test that a non-empty NO_COLOR disables styling."""
+        with patch.dict('os.environ', {'NO_COLOR': '1'}, clear=True):
+            assert not _make_formatter().enable_color
+        with patch.dict('os.environ', {'NO_COLOR': ''}, clear=True):
+            assert _make_formatter().enable_color
+
+
+class TestDisplayWidth:
+    """Test cases for terminal cell width handling."""
+
+    def setup_method(self):
+        """Set up test fixtures."""
+        self.formatter = _make_formatter(no_color=True)
+
+    def test_wide_and_zero_width_characters(self):
+        """Test widths of CJK, combining marks and emoji sequences."""
+        width = self.formatter._display_width
+        assert width('abc') == 3
+        assert width('日本語') == 6
+        assert width('e\u0301') == 1          # e + combining acute
+        # Variation selectors add no width of their own
+        assert width('\u2764\ufe0f') == width('\u2764')
+        assert width('a\u200db\u2060c') == 3   # joiners are zero width
+        assert width('soft\u00adhyphen') == 11  # soft hyphen takes a cell
+        assert width('\033[92m漢字\033[0m') == 4
+
+    def test_truncate_wide_text(self):
+        """Test that truncation never exceeds the cell budget."""
+        truncated = self.formatter._truncate_text('日本語のテキスト', 7)
+        assert truncated == '日本語…'
+        assert self.formatter._display_width(truncated) <= 7
+
+    def test_reverse_truncate_pads_by_display_width(self):
+        """Test that right alignment ignores escape codes and wide chars."""
+        line = '\033[31m*\033[0m 漢'
+        result = self.formatter._reverse_truncate(line, 10)
+        assert self.formatter._display_width(result) == 10
+
+    def test_reverse_truncate_plain_prefix(self):
+        """Test that uncolored text before the first escape code counts."""
+        line = '--漢\033[31m*\033[0m'
+        padded = self.formatter._reverse_truncate(line, 10)
+        assert padded == ' ' * 5 + line
+        cut = self.formatter._reverse_truncate(line, 4)
+        assert self.formatter._strip_ansi(cut) == '-漢*'
+
+    def test_reverse_truncate_plain_line(self):
+        """Test that escape-free lines (e.g. --no-color) are cut too."""
+        line = '-' * 30 + '*'
+        result = self.formatter._reverse_truncate(line, 10)
+        assert result == '-' * 9 + '*'
+
+    def test_user_info_underline_matches_name_width(self):
+        """Test that the underline spans a CJK name's full width."""
+        lines = self.formatter._format_user_info({'name': '山田太郎'}, {
+            'total_contributions': 5})
+        assert self.formatter._display_width(lines[0]) == \
+            self.formatter._display_width(lines[1])
@@ -0,0 +1,88 @@
+"""
+Tests for local git repository helpers
+"""
+
+import os
+import subprocess
+import tempfile
+from pathlib import Path
+from unittest.mock import patch
+
+from gitfetch.fetcher import (get_git_executable, get_local_repo_root,
+                              is_git_available, git_not_found_message)
+
+
+class TestGitExecutable:
+    """Test cases for resolving the git executable."""
+
+    def test_git_executable_override(self):
+        """Test that $GIT overrides the git executable."""
+        with patch.dict('os.environ', {'GIT': '/opt/git/bin/git'}):
+            assert get_git_executable() == '/opt/git/bin/git'
+        with patch.dict('os.environ', {}, clear=True):
+            assert get_git_executable() == 'git'
+
+    def test_missing_git_executable(self):
+        """Test that a missing git binary is detected with a clear message."""
+        missing = os.path.join(tempfile.gettempdir(), "gitfetch-no-such-git")
+        with patch.dict('os.environ', {'GIT': missing}):
+            assert not is_git_available()
+            assert missing in git_not_found_message()
+        with patch.dict('os.environ', {}, clear=True):
+            assert git_not_found_message() == "Error: git not found on PATH"
+        assert is_git_available()
+
+
+class TestLocalRepo:
+    """Test cases for locating the local repository."""
+
+    def setup_method(self):
+        """Set up a throwaway work tree and a bare clone."""
+        self.temp_dir = tempfile.mkdtemp()
+        self.work_tree = Path(self.temp_dir) / "work"
+        self.bare_dir = Path(self.temp_dir) / "bare.git"
+        subprocess.run(['git', 'init', '-q', str(self.work_tree)], check=True)
+        subprocess.run(
+            ['git', '-c', 'user.name=test', '-c', 'user.email=test@test',
+             '-c', 'commit.gpgsign=false',
+             'commit', '-q', '--allow-empty', '-m', 'initial'],
+            cwd=self.work_tree, check=True)
+        subprocess.run(['git', 'clone', '-q', '--bare', str(self.work_tree),
+                        str(self.bare_dir)], check=True)
+
+    def teardown_method(self):
+        """Clean up test fixtures."""
+        import shutil
+        shutil.rmtree(self.temp_dir, ignore_errors=True)
+
+    def test_repo_root_from_subdirectory(self):
+        """Test that the work tree root is found from a subdirectory."""
+        subdir = self.work_tree / "nested" / "dir"
+        subdir.mkdir(parents=True)
+        root = get_local_repo_root(str(subdir))
+        assert os.path.samefile(root, self.work_tree)
+
+    def test_repo_root_outside_repository(self):
+        """Test that a plain directory is not treated as a repository."""
+        plain = Path(self.temp_dir) / "plain"
+        plain.mkdir()
+        env = {'GIT_CEILING_DIRECTORIES': self.temp_dir}
+        with patch.dict('os.environ', env):
+            assert get_local_repo_root(str(plain)) is None
+
+    def test_repo_root_with_git_dir(self):
+        """Test that $GIT_DIR pointing at a bare repository is honored."""
+        plain = Path(self.temp_dir) / "plain"
+        plain.mkdir()
+        with patch.dict('os.environ', {'GIT_DIR': str(self.bare_dir)}):
+            root = get_local_repo_root(str(plain))
+        assert os.path.samefile(root, self.bare_dir)
+
+    def test_repo_root_with_git_dir_and_work_tree(self):
+        """Test the dotfiles setup: bare $GIT_DIR plus a separate work tree."""
+        home = Path(self.temp_dir) / "home"
+        home.mkdir()
+        env = {'GIT_DIR': str(self.bare_dir), 'GIT_WORK_TREE': str(home)}
+        with patch.dict('os.environ', env):
+            root = get_local_repo_root(str(home))
+        assert os.path.samefile(root, home)